@@ -0,0 +1,101 @@
+//! Minimal [BlurHash](https://blurha.sh) encoder.
+//!
+//! Produces the short base83 string the front-end expands into a blurred
+//! placeholder while the full `/api/icon` image is still loading.
+
+use image::RgbImage;
+
+const BASE83: &[u8; 83] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+fn srgb_to_linear(value: u8) -> f64 {
+    let v = value as f64 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f64) -> u32 {
+    let v = value.clamp(0.0, 1.0);
+    if v <= 0.003_130_8 {
+        (v * 12.92 * 255.0 + 0.5) as u32
+    } else {
+        ((1.055 * v.powf(1.0 / 2.4) - 0.055) * 255.0 + 0.5) as u32
+    }
+}
+
+fn encode_base83(value: u32, length: u32) -> String {
+    (1..=length)
+        .map(|i| {
+            let digit = (value / 83u32.pow(length - i)) % 83;
+            BASE83[digit as usize] as char
+        })
+        .collect()
+}
+
+fn quantise_ac(value: f64, maximum: f64) -> u32 {
+    let v = (value / maximum).signum() * (value / maximum).abs().sqrt() * 9.0 + 9.5;
+    (v.floor() as i32).clamp(0, 18) as u32
+}
+
+/// Encode `image` into a BlurHash string with `components_x`×`components_y`
+/// basis components (each in `1..=9`).
+pub fn encode(components_x: u32, components_y: u32, image: &RgbImage) -> String {
+    let (width, height) = image.dimensions();
+    let scale = 1.0 / (width * height) as f64;
+
+    let mut factors = Vec::with_capacity((components_x * components_y) as usize);
+    for j in 0..components_y {
+        for i in 0..components_x {
+            let normalisation = if i == 0 && j == 0 { 1.0 } else { 2.0 };
+            let mut sum = [0.0f64; 3];
+            for y in 0..height {
+                for x in 0..width {
+                    let basis = normalisation
+                        * (std::f64::consts::PI * i as f64 * x as f64 / width as f64).cos()
+                        * (std::f64::consts::PI * j as f64 * y as f64 / height as f64).cos();
+                    let pixel = image.get_pixel(x, y);
+                    sum[0] += basis * srgb_to_linear(pixel[0]);
+                    sum[1] += basis * srgb_to_linear(pixel[1]);
+                    sum[2] += basis * srgb_to_linear(pixel[2]);
+                }
+            }
+            factors.push([sum[0] * scale, sum[1] * scale, sum[2] * scale]);
+        }
+    }
+
+    let dc = factors[0];
+    let ac = &factors[1..];
+
+    let mut hash = String::new();
+
+    let size_flag = (components_x - 1) + (components_y - 1) * 9;
+    hash.push_str(&encode_base83(size_flag, 1));
+
+    let maximum_ac = ac
+        .iter()
+        .flat_map(|c| c.iter().copied())
+        .map(f64::abs)
+        .fold(0.0f64, f64::max);
+    let (quantised_max, maximum) = if ac.is_empty() {
+        (0, 1.0)
+    } else {
+        let quantised = ((maximum_ac * 166.0 - 0.5).floor() as i32).clamp(0, 82) as u32;
+        (quantised, (quantised + 1) as f64 / 166.0)
+    };
+    hash.push_str(&encode_base83(quantised_max, 1));
+
+    let dc_value = (linear_to_srgb(dc[0]) << 16) + (linear_to_srgb(dc[1]) << 8) + linear_to_srgb(dc[2]);
+    hash.push_str(&encode_base83(dc_value, 4));
+
+    for component in ac {
+        let value = quantise_ac(component[0], maximum) * 19 * 19
+            + quantise_ac(component[1], maximum) * 19
+            + quantise_ac(component[2], maximum);
+        hash.push_str(&encode_base83(value, 2));
+    }
+
+    hash
+}
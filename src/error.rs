@@ -1,14 +1,47 @@
-use axum::{body::Body, response::{IntoResponse, Response}};
+use axum::{
+    body::Body,
+    response::{IntoResponse, Response},
+    Json,
+};
 use http::StatusCode;
+use serde::Serialize;
 
 pub struct AppError(anyhow::Error);
 
 pub type AppResult<T> = Result<T, AppError>;
 
+/// Uniform envelope for the JSON API responses: `/api/list`, the control
+/// endpoints, and every [`AppError`].
+///
+/// Front-end code switches on `type` instead of juggling status codes,
+/// bodies, and content types: `Success` carries the payload, `Failure`
+/// marks a recoverable condition (player busy, can't seek, not found),
+/// and `Fatal` reports an unexpected server error. The `/api/metadata`
+/// event stream and the `/api/icon` image responses are not wrapped; the
+/// icon endpoint answers a missing player or missing art with a plain-text
+/// 404 and a failed remote fetch with a plain-text 502.
+#[derive(Serialize)]
+#[serde(tag = "type", content = "content")]
+pub enum ApiResponse<T> {
+    Success(T),
+    Failure(String),
+    Fatal(String),
+}
+
+impl<T: Serialize> IntoResponse for ApiResponse<T> {
+    fn into_response(self) -> Response<Body> {
+        let status = match self {
+            ApiResponse::Success(_) | ApiResponse::Failure(_) => StatusCode::OK,
+            ApiResponse::Fatal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, Json(self)).into_response()
+    }
+}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response<Body> {
         log::error!("{:?}", self.0);
-        (StatusCode::INTERNAL_SERVER_ERROR, self.0.to_string()).into_response()
+        ApiResponse::<()>::Fatal(self.0.to_string()).into_response()
     }
 }
 
@@ -2,6 +2,8 @@ use std::{
     convert::Infallible,
     hash::{Hash, Hasher},
     net::SocketAddr,
+    path::PathBuf,
+    sync::OnceLock,
     time::Duration,
 };
 
@@ -12,28 +14,170 @@ use axum::{
     http::Response,
     response::IntoResponse,
     routing::{get, post},
-    Json, Router,
+    Router,
 };
 use bytes::Bytes;
 use clap::Parser;
 use futures::StreamExt;
-use http::{status::StatusCode, HeaderValue};
+use http::{status::StatusCode, HeaderMap, HeaderValue};
 use mime_guess::MimeGuess;
 use mpris::{DBusError, Player, PlayerFinder};
-use serde::Serialize;
-use tokio::{fs::File, net::TcpListener, sync::watch};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt},
+    net::TcpListener,
+    sync::watch,
+};
 use tokio_stream::wrappers::{IntervalStream, WatchStream};
 use tower_http::{services::ServeDir, set_header::SetResponseHeaderLayer};
 
+mod blurhash;
 mod error;
 
-use error::AppResult;
+use error::{ApiResponse, AppResult};
 
 const PUBLIC_DIR: &str = match std::option_env!("PUBLIC_DIR") {
     Some(dir) => dir,
     None => "dist",
 };
 
+/// Shared client for remote art fetches, configured with the request
+/// timeout from `--art-timeout` before the server starts serving.
+static HTTP_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Remote art-fetch timeout, shared with the blocking client used for
+/// blurhash computation off the async runtime.
+static ART_TIMEOUT: OnceLock<Duration> = OnceLock::new();
+
+/// Blocking client for blurhash art decoding. Built lazily inside the
+/// metadata worker thread so it never runs on the async runtime, and
+/// configured with the same `--art-timeout` as [`HTTP_CLIENT`] so a hung
+/// cover-art host can't stall live metadata updates indefinitely.
+static BLOCKING_CLIENT: OnceLock<reqwest::blocking::Client> = OnceLock::new();
+
+/// Maximum number of attempts (initial + retries) for a remote art fetch.
+const ART_FETCH_ATTEMPTS: u32 = 3;
+
+/// Cache policy for `/icon` responses: the `:hash` path segment pins the art
+/// version, so a cover can be cached indefinitely.
+const ICON_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Default listen address when neither the config file nor `--listen-on` set one.
+const DEFAULT_LISTEN_ON: &str = "127.0.0.1:8000";
+
+/// Default remote art-fetch timeout, in seconds.
+const DEFAULT_ART_TIMEOUT: f64 = 10.0;
+
+/// Optional TOML configuration file. Every field is optional; CLI flags take
+/// precedence and a missing file falls back to the built-in defaults.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Config {
+    listen_on: Option<SocketAddr>,
+    art_timeout: Option<f64>,
+    /// Bus-name patterns (`*` wildcard) that a player must match to be listed
+    /// or controlled. An empty list allows every player.
+    allow: Vec<String>,
+    /// Bus-name patterns (`*` wildcard) hidden from `/api/list` and blocked
+    /// from the control endpoints, taking precedence over `allow`.
+    deny: Vec<String>,
+}
+
+/// Resolved allow/denylist, shared with the player lookup helpers.
+static PLAYER_FILTER: OnceLock<PlayerFilter> = OnceLock::new();
+
+#[derive(Default)]
+struct PlayerFilter {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+impl PlayerFilter {
+    /// Whether a player with the well-known bus name `bus_name` (e.g.
+    /// `org.mpris.MediaPlayer2.firefox`) is visible and controllable.
+    fn is_allowed(&self, bus_name: &str) -> bool {
+        let allowed = self.allow.is_empty()
+            || self
+                .allow
+                .iter()
+                .any(|pattern| pattern_matches(pattern, bus_name));
+        allowed
+            && !self
+                .deny
+                .iter()
+                .any(|pattern| pattern_matches(pattern, bus_name))
+    }
+}
+
+/// Match a bus-name `pattern` against `name`, treating `*` as a wildcard that
+/// spans any (possibly empty) run of characters.
+fn pattern_matches(pattern: &str, name: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == name;
+    }
+    if !name.starts_with(parts[0]) {
+        return false;
+    }
+    let mut pos = parts[0].len();
+    for (idx, part) in parts.iter().enumerate().skip(1) {
+        if idx == parts.len() - 1 {
+            if name.len() < pos + part.len() || !name[pos..].ends_with(part) {
+                return false;
+            }
+        } else if !part.is_empty() {
+            match name[pos..].find(part) {
+                Some(found) => pos += found + part.len(),
+                None => return false,
+            }
+        }
+    }
+    true
+}
+
+/// Percent-decode the payload of a non-base64 `data:` URI into raw bytes.
+fn percent_decode(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Ok(byte) = u8::from_str_radix(&input[i + 1..i + 3], 16) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Parse a single-range `Range` header value against a resource of `size`
+/// bytes, returning the inclusive `(start, end)` byte offsets.
+fn parse_range(value: &str, size: u64) -> Option<(u64, u64)> {
+    let spec = value.strip_prefix("bytes=")?;
+    let (start, end) = spec.split_once('-')?;
+    let (start, end) = if start.is_empty() {
+        let suffix: u64 = end.parse().ok()?;
+        if suffix == 0 {
+            return None;
+        }
+        (size.saturating_sub(suffix), size - 1)
+    } else {
+        let start: u64 = start.parse().ok()?;
+        let end = if end.is_empty() {
+            size - 1
+        } else {
+            end.parse::<u64>().ok()?.min(size - 1)
+        };
+        (start, end)
+    };
+    (start <= end && start < size).then_some((start, end))
+}
+
 fn find_player_by_id(id: &str) -> Result<Option<Player>, DBusError> {
     let player_finder = PlayerFinder::new()?;
     let player = player_finder
@@ -44,21 +188,114 @@ fn find_player_by_id(id: &str) -> Result<Option<Player>, DBusError> {
                 .is_ok_and(|player| player.unique_name() != id)
         })
         .transpose()?;
+    // The id is the ephemeral unique connection name; filter on the stable
+    // well-known bus name, which is only known once the player is resolved.
+    if let Some(player) = &player {
+        if PLAYER_FILTER
+            .get()
+            .is_some_and(|filter| !filter.is_allowed(player.bus_name()))
+        {
+            return Ok(None);
+        }
+    }
     Ok(player)
 }
 
+/// Decode the art at `art_url` (a `file://` path or `http(s)://` URL) down
+/// to a small working size and encode it as a BlurHash placeholder.
+fn compute_blurhash(art_url: &str) -> anyhow::Result<String> {
+    let image = if let Some(path) = art_url.strip_prefix("file://") {
+        image::open(path)?
+    } else if art_url.starts_with("http") {
+        let client = BLOCKING_CLIENT.get_or_init(|| {
+            let timeout = ART_TIMEOUT
+                .get()
+                .copied()
+                .unwrap_or_else(|| Duration::from_secs_f64(DEFAULT_ART_TIMEOUT));
+            reqwest::blocking::Client::builder()
+                .timeout(timeout)
+                .build()
+                .expect("failed to build blocking HTTP client")
+        });
+        let bytes = client.get(art_url).send()?.bytes()?;
+        image::load_from_memory(&bytes)?
+    } else {
+        return Err(anyhow!("Unsupported art URL for blurhash: {}", art_url));
+    };
+    let small = image.thumbnail(64, 64).to_rgb8();
+    Ok(blurhash::encode(4, 3, &small))
+}
+
 #[tokio::main]
 async fn main() {
     #[derive(clap::Parser)]
     struct Args {
-        #[clap(long, default_value = "127.0.0.1:8000")]
-        listen_on: SocketAddr,
+        #[clap(long)]
+        listen_on: Option<SocketAddr>,
+
+        /// Timeout, in seconds, for fetching remote `http(s)://` album art.
+        #[clap(long)]
+        art_timeout: Option<f64>,
+
+        /// Path to an optional TOML configuration file.
+        #[clap(long)]
+        config: Option<PathBuf>,
     }
 
     env_logger::init();
 
     let args = Args::parse();
 
+    // A missing file falls back to defaults; a present but malformed one is
+    // an error worth stopping for.
+    let config: Config = match args.config {
+        Some(path) => match std::fs::read_to_string(&path) {
+            Ok(contents) => match toml::from_str(&contents) {
+                Ok(config) => config,
+                Err(err) => {
+                    eprintln!("Failed to parse config file {}: {}", path.display(), err);
+                    std::process::exit(1);
+                }
+            },
+            // A genuinely missing file falls back to the built-in defaults; any
+            // other read or parse failure is surfaced as a clean error and exit.
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                log::warn!("Config file {} not found; using defaults", path.display());
+                Config::default()
+            }
+            Err(err) => {
+                eprintln!("Failed to read config file {}: {}", path.display(), err);
+                std::process::exit(1);
+            }
+        },
+        None => Config::default(),
+    };
+
+    // CLI flags override file values, which override the built-in defaults.
+    let listen_on = args
+        .listen_on
+        .or(config.listen_on)
+        .unwrap_or_else(|| DEFAULT_LISTEN_ON.parse().unwrap());
+    let art_timeout = args
+        .art_timeout
+        .or(config.art_timeout)
+        .unwrap_or(DEFAULT_ART_TIMEOUT);
+
+    PLAYER_FILTER
+        .set(PlayerFilter {
+            allow: config.allow,
+            deny: config.deny,
+        })
+        .ok();
+
+    ART_TIMEOUT.set(Duration::from_secs_f64(art_timeout)).ok();
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs_f64(art_timeout))
+        .build()
+        .unwrap();
+    HTTP_CLIENT.set(client).unwrap();
+
     let static_service = ServeDir::new(PUBLIC_DIR).append_index_html_on_directories(true);
 
     let api_router = Router::new()
@@ -66,6 +303,8 @@ async fn main() {
         .route("/metadata/:id", get(metadata))
         .route("/icon/:id/:hash", get(icon))
         .route("/playpause/:id", post(playpause))
+        .route("/volume/:id/:level", post(volume))
+        .route("/rate/:id/:rate", post(rate))
         .route("/seek/:id/:dtime", post(seek))
         .route("/next/:id", post(next))
         .route("/prev/:id", post(prev))
@@ -78,18 +317,23 @@ async fn main() {
         .nest_service("/", static_service)
         .nest("/api", api_router);
 
-    let listener = TcpListener::bind(args.listen_on).await.unwrap();
+    let listener = TcpListener::bind(listen_on).await.unwrap();
     axum::serve(listener, app).await.unwrap();
 }
 
 #[axum::debug_handler]
-async fn list() -> AppResult<impl IntoResponse> {
+async fn list() -> AppResult<ApiResponse<Vec<String>>> {
     let player_finder = PlayerFinder::new()?;
+    let filter = PLAYER_FILTER.get();
     let vec = player_finder
         .iter_players()?
+        .filter(|player| match player {
+            Ok(player) => filter.is_none_or(|filter| filter.is_allowed(player.bus_name())),
+            Err(_) => true,
+        })
         .map(|player| player.map(|player| player.unique_name().to_owned()))
         .collect::<Result<Vec<_>, _>>()?;
-    Ok(Json(vec))
+    Ok(ApiResponse::Success(vec))
 }
 
 #[axum::debug_handler]
@@ -102,6 +346,7 @@ async fn metadata(Path(id): Path<String>) -> Response<Body> {
         running: bool,
         playback_rate: Option<f64>,
         art_url_hash: u64,
+        blurhash: Option<String>,
 
         can_control: bool,
         can_go_next: bool,
@@ -114,12 +359,29 @@ async fn metadata(Path(id): Path<String>) -> Response<Body> {
 
     fn update_watch(id: &str, tx: watch::Sender<Option<Info>>) -> anyhow::Result<()> {
         let player = find_player_by_id(id)?.context("Player not found")?;
+        // Cache the last computed blurhash so it is only recomputed when the
+        // art (identified by `art_url_hash`) actually changes.
+        let mut blurhash_cache: Option<(u64, Option<String>)> = None;
         for () in [()].into_iter().chain(player.events()?.map(|_| ())) {
             let metadata = player.get_metadata()?;
             let art_url = metadata.art_url();
             let mut hasher = std::hash::DefaultHasher::new();
             art_url.hash(&mut hasher);
             let art_url_hash = hasher.finish();
+            let blurhash = match &blurhash_cache {
+                Some((hash, blurhash)) if *hash == art_url_hash => blurhash.clone(),
+                _ => {
+                    let blurhash = art_url.and_then(|art_url| match compute_blurhash(art_url) {
+                        Ok(blurhash) => Some(blurhash),
+                        Err(err) => {
+                            log::warn!("Failed to compute blurhash for {}: {:?}", art_url, err);
+                            None
+                        }
+                    });
+                    blurhash_cache = Some((art_url_hash, blurhash.clone()));
+                    blurhash
+                }
+            };
             let info = Info {
                 position: player.get_position_in_microseconds()?,
                 length: metadata.length_in_microseconds(),
@@ -127,6 +389,7 @@ async fn metadata(Path(id): Path<String>) -> Response<Body> {
                 running: player.get_playback_status()? == mpris::PlaybackStatus::Playing,
                 playback_rate: player.get_playback_rate().ok(),
                 art_url_hash,
+                blurhash,
 
                 can_control: player.can_control()?,
                 can_go_next: player.can_go_next()?,
@@ -179,7 +442,25 @@ async fn metadata(Path(id): Path<String>) -> Response<Body> {
 }
 
 #[axum::debug_handler]
-async fn icon(Path((id, _hash)): Path<(String, u64)>) -> AppResult<Response<Body>> {
+async fn icon(
+    Path((id, hash)): Path<(String, u64)>,
+    headers: HeaderMap,
+) -> AppResult<Response<Body>> {
+    let etag = format!("\"{hash}\"");
+
+    if headers
+        .get(http::header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value == etag)
+    {
+        return Ok(Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .header(http::header::ETAG, &etag)
+            .header(http::header::CACHE_CONTROL, ICON_CACHE_CONTROL)
+            .body(Body::empty())
+            .unwrap());
+    }
+
     let art_url = {
         let Some(player) = find_player_by_id(&id)? else {
             return Ok((StatusCode::NOT_FOUND, "Player not found\n").into_response());
@@ -192,19 +473,72 @@ async fn icon(Path((id, _hash)): Path<(String, u64)>) -> AppResult<Response<Body
     };
 
     if let Some(path) = art_url.strip_prefix("file://") {
-        let file = File::open(path).await?;
-        let content_length = file.metadata().await?.len();
+        let mut file = File::open(path).await?;
+        let size = file.metadata().await?.len();
         let mime = MimeGuess::from_path(path).first_or(mime::IMAGE_STAR);
+
+        let range = headers
+            .get(http::header::RANGE)
+            .filter(|_| size > 0)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| parse_range(value, size));
+
+        if let Some((start, end)) = range {
+            file.seek(std::io::SeekFrom::Start(start)).await?;
+            let length = end - start + 1;
+            let body = Body::from_stream(tokio_util::io::ReaderStream::new(file.take(length)));
+            return Ok(Response::builder()
+                .status(StatusCode::PARTIAL_CONTENT)
+                .header("Content-Length", length)
+                .header("Content-Type", mime.as_ref())
+                .header("Content-Range", format!("bytes {start}-{end}/{size}"))
+                .header("Accept-Ranges", "bytes")
+                .header("ETag", &etag)
+                .header("Cache-Control", ICON_CACHE_CONTROL)
+                .body(body)
+                .unwrap());
+        }
+
         let body = Body::from_stream(tokio_util::io::ReaderStream::new(file));
         return Ok(Response::builder()
-            .header("Content-Length", content_length)
+            .header("Content-Length", size)
             .header("Content-Type", mime.as_ref())
+            .header("Accept-Ranges", "bytes")
+            .header("ETag", &etag)
+            .header("Cache-Control", ICON_CACHE_CONTROL)
             .body(body)
             .unwrap());
     }
 
     if art_url.starts_with("http") {
-        let response = reqwest::get(art_url).await?;
+        let client = HTTP_CLIENT.get().expect("HTTP client not initialized");
+        let response = {
+            let mut attempt = 0;
+            loop {
+                match client.get(&art_url).send().await {
+                    Ok(response) => break response,
+                    Err(err)
+                        if attempt + 1 < ART_FETCH_ATTEMPTS
+                            && (err.is_timeout() || err.is_connect()) =>
+                    {
+                        let delay = Duration::from_secs(1 << attempt);
+                        log::warn!(
+                            "Art fetch attempt {} for {} failed: {}; retrying in {:?}",
+                            attempt + 1,
+                            art_url,
+                            err,
+                            delay,
+                        );
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                    }
+                    Err(err) => {
+                        log::error!("Art fetch for {} failed: {}", art_url, err);
+                        return Ok((StatusCode::BAD_GATEWAY, "Failed to fetch art\n").into_response());
+                    }
+                }
+            }
+        };
         let content_length = response.headers().get("Content-Length").cloned();
         let content_type = response.headers().get("Content-Type").cloned();
         let body = Body::from_stream(response.bytes_stream());
@@ -215,58 +549,125 @@ async fn icon(Path((id, _hash)): Path<(String, u64)>) -> AppResult<Response<Body
         if let Some(content_type) = content_type {
             res.headers_mut().insert("Content-Type", content_type);
         }
+        res.headers_mut()
+            .insert("ETag", HeaderValue::from_str(&etag).unwrap());
+        res.headers_mut().insert(
+            "Cache-Control",
+            HeaderValue::from_static(ICON_CACHE_CONTROL),
+        );
         return Ok(res);
     }
 
-    // TODO: data:image/jpeg;base64
+    if let Some(rest) = art_url.strip_prefix("data:") {
+        let (meta, data) = rest.split_once(',').context("Malformed data URL")?;
+        let base64 = meta.ends_with(";base64");
+        let mime = meta.strip_suffix(";base64").unwrap_or(meta);
+        let mime = if mime.is_empty() {
+            "text/plain;charset=US-ASCII"
+        } else {
+            mime
+        };
+        let bytes = if base64 {
+            use base64::Engine as _;
+            base64::engine::general_purpose::STANDARD.decode(data)?
+        } else {
+            percent_decode(data)
+        };
+        return Ok(Response::builder()
+            .header("Content-Type", mime)
+            .header("Content-Length", bytes.len())
+            .header("ETag", &etag)
+            .header("Cache-Control", ICON_CACHE_CONTROL)
+            .body(Body::from(bytes))
+            .unwrap());
+    }
 
     Err(anyhow!("Unsupported art URL: {}", art_url))?
 }
 
 #[axum::debug_handler]
-async fn playpause(Path(id): Path<String>) -> AppResult<impl IntoResponse> {
+async fn playpause(Path(id): Path<String>) -> AppResult<ApiResponse<&'static str>> {
     let Some(player) = find_player_by_id(&id)? else {
-        return Ok((StatusCode::NOT_FOUND, "Player not found\n"));
+        return Ok(ApiResponse::Failure("Player not found".into()));
     };
     let res = player.checked_play_pause()?;
     if !res {
-        return Ok((StatusCode::BAD_REQUEST, "Cannot play/pause\n"));
+        return Ok(ApiResponse::Failure("Cannot play/pause".into()));
+    }
+    Ok(ApiResponse::Success("Operation successfull"))
+}
+
+#[axum::debug_handler]
+async fn volume(Path((id, level)): Path<(String, f64)>) -> AppResult<ApiResponse<&'static str>> {
+    let Some(player) = find_player_by_id(&id)? else {
+        return Ok(ApiResponse::Failure("Player not found".into()));
+    };
+    if !(0.0..=1.0).contains(&level) {
+        return Ok(ApiResponse::Failure("Volume out of range (0.0–1.0)".into()));
+    }
+    if !player.set_volume_checked(level)? {
+        return Ok(ApiResponse::Failure("Cannot set volume".into()));
+    }
+    Ok(ApiResponse::Success("Operation successfull"))
+}
+
+#[axum::debug_handler]
+async fn rate(Path((id, rate)): Path<(String, f64)>) -> AppResult<ApiResponse<&'static str>> {
+    let Some(player) = find_player_by_id(&id)? else {
+        return Ok(ApiResponse::Failure("Player not found".into()));
+    };
+    // A zero or non-finite rate is never valid. `MinimumRate`/`MaximumRate`
+    // are optional MPRIS properties, so only narrow the range when both can be
+    // read — a missing property must not turn a valid request into a 500.
+    if !rate.is_finite() || rate <= 0.0 {
+        return Ok(ApiResponse::Failure("Rate out of range".into()));
+    }
+    if let (Ok(minimum), Ok(maximum)) = (
+        player.get_minimum_playback_rate(),
+        player.get_maximum_playback_rate(),
+    ) {
+        if rate < minimum || rate > maximum {
+            return Ok(ApiResponse::Failure("Rate out of range".into()));
+        }
+    }
+    if !player.checked_set_playback_rate(rate)? {
+        return Ok(ApiResponse::Failure("Cannot set rate".into()));
     }
-    Ok((StatusCode::OK, "Operation successfull\n"))
+    Ok(ApiResponse::Success("Operation successfull"))
 }
 
 #[axum::debug_handler]
-async fn seek(Path((id, dtime)): Path<(String, i64)>) -> AppResult<impl IntoResponse> {
+async fn seek(Path((id, dtime)): Path<(String, i64)>) -> AppResult<ApiResponse<&'static str>> {
     let Some(player) = find_player_by_id(&id)? else {
-        return Ok((StatusCode::NOT_FOUND, "Player not found\n"));
+        return Ok(ApiResponse::Failure("Player not found".into()));
     };
     let res = player.checked_seek(dtime)?;
     if !res {
-        return Ok((StatusCode::BAD_REQUEST, "Cannot seek\n"));
+        return Ok(ApiResponse::Failure("Cannot seek".into()));
     }
-    Ok((StatusCode::OK, "Operation successfull\n"))
+    Ok(ApiResponse::Success("Operation successfull"))
 }
 
 #[axum::debug_handler]
-async fn next(Path(id): Path<String>) -> AppResult<impl IntoResponse> {
+async fn next(Path(id): Path<String>) -> AppResult<ApiResponse<&'static str>> {
     let Some(player) = find_player_by_id(&id)? else {
-        return Ok((StatusCode::NOT_FOUND, "Player not found\n"));
+        return Ok(ApiResponse::Failure("Player not found".into()));
     };
     let res = player.checked_next()?;
     if !res {
-        return Ok((StatusCode::BAD_REQUEST, "Cannot go to next track\n"));
+        return Ok(ApiResponse::Failure("Cannot go to next track".into()));
     }
-    Ok((StatusCode::OK, "Operation successfull\n"))
+    Ok(ApiResponse::Success("Operation successfull"))
 }
 
 #[axum::debug_handler]
-async fn prev(Path(id): Path<String>) -> AppResult<impl IntoResponse> {
+async fn prev(Path(id): Path<String>) -> AppResult<ApiResponse<&'static str>> {
     let Some(player) = find_player_by_id(&id)? else {
-        return Ok((StatusCode::NOT_FOUND, "Player not found\n"));
+        return Ok(ApiResponse::Failure("Player not found".into()));
     };
     let res = player.checked_previous()?;
     if !res {
-        return Ok((StatusCode::BAD_REQUEST, "Cannot go to previous track\n"));
+        return Ok(ApiResponse::Failure("Cannot go to previous track".into()));
     }
-    Ok((StatusCode::OK, "Operation successfull\n"))
+    Ok(ApiResponse::Success("Operation successfull"))
 }